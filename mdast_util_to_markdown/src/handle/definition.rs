@@ -7,7 +7,10 @@ use markdown::{
 use crate::{
     construct_name::ConstructName,
     state::{Info, State},
-    util::{check_quote::check_quote, safe::SafeConfig},
+    util::{
+        check_quote::{check_quote, check_quote_for_title},
+        safe::SafeConfig,
+    },
 };
 
 use super::Handle;
@@ -50,21 +53,24 @@ impl Handle for Definition {
         state.exit();
 
         if let Some(title) = &self.title {
-            let title_construct_name = if quote == '"' {
-                ConstructName::TitleQuote
-            } else {
-                ConstructName::TitleApostrophe
+            let marker = check_quote_for_title(quote, title);
+
+            let title_construct_name = match marker {
+                '"' => ConstructName::TitleQuote,
+                '(' => ConstructName::TitleParen,
+                _ => ConstructName::TitleApostrophe,
             };
+            let closing_marker = if marker == '(' { ')' } else { marker };
 
             state.enter(title_construct_name);
             value.push(' ');
-            value.push(quote);
+            value.push(marker);
 
             let mut before_buffer = [0u8; 4];
-            let before = quote.encode_utf8(&mut before_buffer);
+            let before = closing_marker.encode_utf8(&mut before_buffer);
             value.push_str(&state.safe(title, &SafeConfig::new(&self.url, before, None)));
 
-            value.push(quote);
+            value.push(closing_marker);
             state.exit();
         }
 
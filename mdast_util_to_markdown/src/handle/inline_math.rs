@@ -0,0 +1,64 @@
+use alloc::string::String;
+use markdown::{
+    mdast::{InlineMath, Node},
+    message::Message,
+};
+
+use crate::{construct_name::ConstructName, state::{Info, State}};
+
+use super::Handle;
+
+impl Handle for InlineMath {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::MathText);
+
+        let sequence_size = fence_size(&self.value) + 1;
+        let fence = "$".repeat(sequence_size);
+
+        let pad = needs_padding(&self.value);
+
+        let mut value = fence.clone();
+        if pad {
+            value.push(' ');
+        }
+        value.push_str(&self.value);
+        if pad {
+            value.push(' ');
+        }
+        value.push_str(&fence);
+
+        state.exit();
+
+        Ok(value)
+    }
+}
+
+/// The length of the longest run of `$` in `value`.
+fn fence_size(value: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+
+    for char in value.chars() {
+        if char == '$' {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+
+    longest
+}
+
+/// Whether `value` needs a space of padding on each side: it isn’t only
+/// whitespace, and it starts or ends with a `$` or with whitespace.
+fn needs_padding(value: &str) -> bool {
+    let trimmed = value.trim();
+    !trimmed.is_empty() && (value.starts_with('$') || value.ends_with('$') || value != trimmed)
+}
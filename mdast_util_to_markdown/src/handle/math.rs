@@ -0,0 +1,55 @@
+use alloc::string::String;
+use markdown::{
+    mdast::{Math, Node},
+    message::Message,
+};
+
+use crate::{
+    construct_name::ConstructName,
+    state::{Info, State},
+};
+
+use super::Handle;
+
+impl Handle for Math {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::MathFlow);
+
+        let fence_size = fence_size(&self.value).max(2);
+        let fence = "$".repeat(fence_size);
+
+        let mut value = fence.clone();
+        value.push('\n');
+        value.push_str(&self.value);
+        value.push('\n');
+        value.push_str(&fence);
+
+        state.exit();
+
+        Ok(value)
+    }
+}
+
+/// One more than the length of the longest run of `$` in `value`, so the
+/// fence used to wrap it is always long enough not to be closed early.
+fn fence_size(value: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+
+    for char in value.chars() {
+        if char == '$' {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+
+    longest + 1
+}
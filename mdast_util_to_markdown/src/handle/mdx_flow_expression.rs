@@ -0,0 +1,29 @@
+use alloc::string::String;
+use markdown::{
+    mdast::{MdxFlowExpression, Node},
+    message::Message,
+};
+
+use crate::{construct_name::ConstructName, state::{Info, State}};
+
+use super::Handle;
+
+impl Handle for MdxFlowExpression {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::MdxFlowExpression);
+
+        let mut value = String::from('{');
+        value.push_str(&self.value);
+        value.push('}');
+
+        state.exit();
+
+        Ok(value)
+    }
+}
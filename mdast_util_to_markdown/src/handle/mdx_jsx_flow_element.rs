@@ -0,0 +1,41 @@
+use alloc::{format, string::String};
+use markdown::{
+    mdast::{MdxJsxFlowElement, Node},
+    message::Message,
+};
+
+use crate::{construct_name::ConstructName, state::{Info, State}};
+
+use super::Handle;
+
+impl Handle for MdxJsxFlowElement {
+    fn handle(
+        &self,
+        state: &mut State,
+        info: &Info,
+        _parent: Option<&Node>,
+        node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::MdxJsxFlowElement);
+
+        let name = self.name.as_deref().unwrap_or("");
+        let mut value = format!("<{}", name);
+
+        for attribute in &self.attributes {
+            value.push(' ');
+            value.push_str(&attribute.to_string());
+        }
+
+        if self.children.is_empty() {
+            value.push_str(" />");
+        } else {
+            value.push('>');
+            value.push_str(&state.container_flow(node, info)?);
+            value.push_str(&format!("</{}>", name));
+        }
+
+        state.exit();
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,24 @@
+use alloc::string::String;
+use markdown::{
+    mdast::{MdxjsEsm, Node},
+    message::Message,
+};
+
+use crate::{construct_name::ConstructName, state::{Info, State}};
+
+use super::Handle;
+
+impl Handle for MdxjsEsm {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::MdxjsEsm);
+        let value = self.value.clone();
+        state.exit();
+        Ok(value)
+    }
+}
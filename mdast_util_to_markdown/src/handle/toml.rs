@@ -0,0 +1,32 @@
+use alloc::string::String;
+use markdown::{
+    mdast::{Node, Toml},
+    message::Message,
+};
+
+use crate::{
+    construct_name::ConstructName,
+    state::{Info, State},
+};
+
+use super::Handle;
+
+impl Handle for Toml {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<String, Message> {
+        state.enter(ConstructName::Frontmatter);
+
+        let mut value = String::from("+++\n");
+        value.push_str(&self.value);
+        value.push_str("\n+++");
+
+        state.exit();
+
+        Ok(value)
+    }
+}
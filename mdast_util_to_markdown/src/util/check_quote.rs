@@ -0,0 +1,46 @@
+//! Utility to check which marker is used for titles.
+
+use alloc::{boxed::Box, format, string::String};
+use markdown::message::Message;
+
+use crate::state::State;
+
+/// Check which character is used for quotes.
+///
+/// Defaults to `"`, but checks `options.quote`: either of the quote
+/// markers (`"` or `'`), or `(` to request parenthesized titles (e.g.
+/// `(a)` instead of `"a"` or `'a'`).
+pub fn check_quote(state: &State) -> Result<char, Message> {
+    let marker = state.options.quote;
+
+    if marker != '"' && marker != '\'' && marker != '(' {
+        return Err(Message {
+            place: None,
+            reason: format!(
+                "Cannot serialize title with `{}` for `options.quote`, expected `\"`, `'`, or `(`",
+                marker
+            ),
+            rule_id: Box::from("unexpected-quote"),
+            source: Box::from("markdown-rs"),
+        });
+    }
+
+    Ok(marker)
+}
+
+/// Given the preferred title `marker`, and the `value` that is going to be
+/// wrapped in it, pick the marker actually used: falls back to the
+/// alternate style when `value` already contains the preferred marker.
+///
+/// For quotes, the alternate of `"` is `'` and vice versa, and it is only
+/// used when `value` does not contain the alternate quote too. For
+/// parenthesized titles, the alternate is `"` (there is no sensible
+/// alternate parenthesis marker).
+pub fn check_quote_for_title(marker: char, value: &str) -> char {
+    match marker {
+        '"' if value.contains('"') && !value.contains('\'') => '\'',
+        '\'' if value.contains('\'') && !value.contains('"') => '"',
+        '(' if value.contains('(') || value.contains(')') => '"',
+        other => other,
+    }
+}
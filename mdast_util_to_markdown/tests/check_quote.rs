@@ -0,0 +1,41 @@
+extern crate mdast_util_to_markdown;
+use mdast_util_to_markdown::util::check_quote::check_quote_for_title;
+
+#[test]
+fn check_quote_for_title_parens() {
+    assert_eq!(
+        check_quote_for_title('"', "a title"),
+        '"',
+        "should keep the preferred quote marker when the title doesn’t contain it"
+    );
+
+    assert_eq!(
+        check_quote_for_title('"', "a \"quoted\" title"),
+        '\'',
+        "should fall back to the alternate quote when the title contains the preferred one"
+    );
+
+    assert_eq!(
+        check_quote_for_title('\'', "it's a title"),
+        '"',
+        "should fall back from apostrophe to double quote"
+    );
+
+    assert_eq!(
+        check_quote_for_title('(', "a title"),
+        '(',
+        "should keep parens when the title contains neither paren"
+    );
+
+    assert_eq!(
+        check_quote_for_title('(', "a (title)"),
+        '"',
+        "should fall back to double quotes when the title contains a paren"
+    );
+
+    assert_eq!(
+        check_quote_for_title('(', "a ) title"),
+        '"',
+        "should fall back to double quotes when the title contains a closing paren alone"
+    );
+}
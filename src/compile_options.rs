@@ -0,0 +1,59 @@
+//! Options for compiling headings into a page: offsetting ranks, assigning
+//! unique `id`s, and (optionally) building a table of contents.
+//!
+//! `process_headings` is not called by the HTML compiler: headings
+//! compiled through `micromark`/`micromark_with_options` get no rank
+//! offset, no `id`, and no table of contents. It takes a document-order
+//! list of `(rank, text)` pairs that its caller collects itself.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::util::heading::{apply_offset, build_toc, HeadingRecord, TocItem};
+use crate::util::slug::IdMap;
+
+/// Heading-related compile options.
+#[derive(Debug, Clone, Copy)]
+pub struct HeadingOptions {
+    /// Shift every heading rank by this amount before assigning it an id
+    /// (see [`apply_offset`]).
+    pub heading_offset: i8,
+    /// Whether to also build a table of contents.
+    pub toc: bool,
+}
+
+impl Default for HeadingOptions {
+    /// No offset, no table of contents.
+    fn default() -> Self {
+        Self {
+            heading_offset: 0,
+            toc: false,
+        }
+    }
+}
+
+/// Turn a document-order list of `(rank, text)` headings into
+/// [`HeadingRecord`]s (rank offset applied, unique id assigned) and,
+/// if `options.toc` is turned on, a table of contents built from them.
+pub fn process_headings(
+    options: &HeadingOptions,
+    headings: &[(u8, String)],
+) -> (Vec<HeadingRecord>, Vec<TocItem>) {
+    let mut ids = IdMap::new();
+    let records: Vec<HeadingRecord> = headings
+        .iter()
+        .map(|(rank, text)| HeadingRecord {
+            rank: apply_offset(*rank, options.heading_offset),
+            id: ids.unique(text),
+            text: text.clone(),
+        })
+        .collect();
+
+    let toc = if options.toc {
+        build_toc(&records)
+    } else {
+        Vec::new()
+    };
+
+    (records, toc)
+}
@@ -0,0 +1,296 @@
+//! The frontmatter construct.
+//!
+//! Frontmatter is a YAML or TOML metadata block that, when turned on, may
+//! occur once, right at the start of the document, before anything else
+//! (including blank lines).
+//!
+//! The grammar for frontmatter is:
+//!
+//! ```bnf
+//! frontmatter ::= fence *space_or_tab eol *line closing_fence *space_or_tab (eol | eof)
+//! fence ::= 3*'-' | 3*'+'
+//! closing_fence ::= 3*'-' | 3*'+'
+//! ```
+//!
+//! The marker of the closing fence must match the marker of the opening
+//! fence, and the closing fence must be at least as long as the opening
+//! fence. If no closing fence is found, the whole construct is not
+//! recognized.
+//!
+//! Frontmatter is not Markdown, so it has no representation in HTML.
+//!
+//! ## References
+//!
+//! * [`micromark-extension-frontmatter`](https://github.com/micromark/micromark-extension-frontmatter)
+//!
+//! [html]: crate::to_html
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Kind of frontmatter, so the closing fence can be matched against the
+/// opening one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// In YAML frontmatter, fenced with dashes.
+    ///
+    /// ```markdown
+    /// ---
+    /// title: Neptune
+    /// ---
+    /// ```
+    Yaml,
+    /// In TOML frontmatter, fenced with plusses.
+    ///
+    /// ```markdown
+    /// +++
+    /// title = "Neptune"
+    /// +++
+    /// ```
+    Toml,
+}
+
+impl Kind {
+    /// The character used for this kind’s fence.
+    fn marker(self) -> char {
+        match self {
+            Kind::Yaml => '-',
+            Kind::Toml => '+',
+        }
+    }
+}
+
+/// Start of frontmatter.
+///
+/// This construct must be attempted exactly once, before anything else,
+/// at the very start of the document: it is not a normal flow construct
+/// and must not be recognized inside a container.
+///
+/// ```markdown
+/// |---
+/// |title: Neptune
+/// |---
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == '-' || char == '+' => {
+            let kind = if char == '-' { Kind::Yaml } else { Kind::Toml };
+            tokenizer.enter(TokenType::Frontmatter);
+            tokenizer.enter(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::FrontmatterSequence);
+            sequence_open(tokenizer, code, kind, 0)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// In the opening fence sequence.
+///
+/// ```markdown
+/// |---
+/// title: Neptune
+/// ---
+/// ```
+fn sequence_open(tokenizer: &mut Tokenizer, code: Code, kind: Kind, size: usize) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == kind.marker() => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| sequence_open(t, c, kind, size + 1))),
+                None,
+            )
+        }
+        _ if size >= 3 => {
+            tokenizer.exit(TokenType::FrontmatterSequence);
+            fence_whitespace_before(tokenizer, code, kind, size)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// After the opening sequence, before optional whitespace.
+fn fence_whitespace_before(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.enter(TokenType::Whitespace);
+            fence_whitespace_inside(tokenizer, code, kind, opening_size)
+        }
+        _ => fence_end(tokenizer, code, kind, opening_size),
+    }
+}
+
+/// In whitespace after the opening sequence.
+fn fence_whitespace_inside(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    fence_whitespace_inside(t, c, kind, opening_size)
+                })),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.exit(TokenType::Whitespace);
+            fence_end(tokenizer, code, kind, opening_size)
+        }
+    }
+}
+
+/// At the end of the opening fence line: an eol is required.
+fn fence_end(tokenizer: &mut Tokenizer, code: Code, kind: Kind, opening_size: usize) -> StateFnResult {
+    match code {
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (
+                State::Fn(Box::new(move |t, c| content_line_start(t, c, kind, opening_size))),
+                None,
+            )
+        }
+        // No eof here: an opening fence without a closing fence is not
+        // frontmatter.
+        _ => (State::Nok, None),
+    }
+}
+
+/// At the start of a line inside frontmatter: either the closing fence, or
+/// another line of raw content.
+fn content_line_start(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        // No closing fence found before the end of the document.
+        Code::None => (State::Nok, None),
+        Code::Char(char) if char == kind.marker() => {
+            tokenizer.enter(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::FrontmatterSequence);
+            closing_sequence(tokenizer, code, kind, opening_size, 0)
+        }
+        _ => {
+            tokenizer.enter(TokenType::FrontmatterChunk);
+            content_line_inside(tokenizer, code, kind, opening_size)
+        }
+    }
+}
+
+/// Inside a raw content line.
+fn content_line_inside(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::None => (State::Nok, None),
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::FrontmatterChunk);
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (
+                State::Fn(Box::new(move |t, c| content_line_start(t, c, kind, opening_size))),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| content_line_inside(t, c, kind, opening_size))),
+                None,
+            )
+        }
+    }
+}
+
+/// In a candidate closing fence sequence.
+///
+/// The closing fence must be at least as long as the opening fence, not
+/// merely the construct's minimum of three.
+fn closing_sequence(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+    size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == kind.marker() => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    closing_sequence(t, c, kind, opening_size, size + 1)
+                })),
+                None,
+            )
+        }
+        // Long enough: this is the closing fence.
+        _ if size >= 3 && size >= opening_size => {
+            tokenizer.exit(TokenType::FrontmatterSequence);
+            closing_fence_whitespace(tokenizer, code, kind, opening_size)
+        }
+        // Too short to close: the whole line, sequence included, is just
+        // more raw content.
+        _ => {
+            tokenizer.exit(TokenType::FrontmatterSequence);
+            tokenizer.exit(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::FrontmatterChunk);
+            content_line_inside(tokenizer, code, kind, opening_size)
+        }
+    }
+}
+
+/// After the closing sequence, before the required eol or eof.
+fn closing_fence_whitespace(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    kind: Kind,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    closing_fence_whitespace(t, c, kind, opening_size)
+                })),
+                None,
+            )
+        }
+        Code::None => {
+            tokenizer.exit(TokenType::FrontmatterFence);
+            tokenizer.exit(TokenType::Frontmatter);
+            (State::Ok, None)
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            tokenizer.exit(TokenType::Frontmatter);
+            (State::Ok, None)
+        }
+        // Trailing content after the closing fence marker: not a valid
+        // close, so keep looking at later lines.
+        _ => {
+            tokenizer.exit(TokenType::FrontmatterFence);
+            tokenizer.enter(TokenType::FrontmatterChunk);
+            content_line_inside(tokenizer, code, kind, opening_size)
+        }
+    }
+}
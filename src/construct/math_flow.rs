@@ -0,0 +1,44 @@
+//! The math (flow) construct.
+//!
+//! Math (flow) is a fenced block, delimited by a run of two or more `$`
+//! characters, that is turned into a `Math` node and whose content is
+//! passed through untouched (typically, later, to a LaTeX renderer).
+//!
+//! ```markdown
+//! $$
+//! x = y
+//! $$
+//! ```
+//!
+//! It has the same “open fence, raw body, matching close fence” shape as
+//! [code (fenced)][crate::construct::code_fenced], and is implemented with
+//! [`partial_raw_flow`][crate::construct::partial_raw_flow] (which code
+//! (fenced) does not use).
+//!
+//! ## References
+//!
+//! * [`micromark-extension-math`](https://github.com/micromark/micromark-extension-math)
+
+use crate::construct::partial_raw_flow::{start as raw_flow, Parts};
+use crate::tokenizer::{Code, StateFnResult, TokenType, Tokenizer};
+
+/// The parts that make flow math a raw flow construct.
+const PARTS: Parts = Parts {
+    marker: '$',
+    min_size: 2,
+    whole: TokenType::MathFlow,
+    fence: TokenType::MathFlowFence,
+    sequence: TokenType::MathFlowFenceSequence,
+    chunk: TokenType::MathFlowChunk,
+};
+
+/// Start of math (flow).
+///
+/// ```markdown
+/// |$$
+/// x = y
+/// $$
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    raw_flow(tokenizer, code, PARTS)
+}
@@ -0,0 +1,128 @@
+//! The math (text) construct.
+//!
+//! Math (text) is inline math, delimited by equal-length runs of `$`,
+//! analogous to how code (text) spans are delimited by runs of `` ` ``:
+//!
+//! ```markdown
+//! $x$ and $$x = y$$ and even $ x $ (one space of padding is stripped).
+//! ```
+//!
+//! The closing sequence must have exactly as many `$` as the opening one;
+//! a run of a different length is just literal content, same as for code
+//! (text) spans. If no matching closing sequence is found before the end
+//! of the content, the whole thing is not math.
+//!
+//! If there is a single space or tab both right after the opening
+//! sequence and right before the closing sequence, and the content isn’t
+//! only whitespace, that whitespace is stripped when the node’s value is
+//! produced (not here: here it is still part of `MathTextData`, the
+//! stripping happens when turning events into an mdast `InlineMath`).
+//!
+//! ## References
+//!
+//! * [`micromark-extension-math`](https://github.com/micromark/micromark-extension-math)
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Start of math (text).
+///
+/// ```markdown
+/// |$x$
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('$') => {
+            tokenizer.enter(TokenType::MathText);
+            tokenizer.enter(TokenType::MathTextSequence);
+            sequence_open(tokenizer, code, 0)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// In the opening sequence.
+fn sequence_open(tokenizer: &mut Tokenizer, code: Code, size: usize) -> StateFnResult {
+    match code {
+        Code::Char('$') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| sequence_open(t, c, size + 1))),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.exit(TokenType::MathTextSequence);
+            between(tokenizer, code, size)
+        }
+    }
+}
+
+/// Between a sequence and data: either at the end (no data at all), or
+/// before a run of non-`$` data.
+fn between(tokenizer: &mut Tokenizer, code: Code, size_open: usize) -> StateFnResult {
+    match code {
+        Code::None => (State::Nok, None),
+        Code::Char('$') => {
+            tokenizer.enter(TokenType::MathTextSequence);
+            sequence_close(tokenizer, code, size_open, 0)
+        }
+        _ => {
+            tokenizer.enter(TokenType::MathTextData);
+            data(tokenizer, code, size_open)
+        }
+    }
+}
+
+/// In data.
+fn data(tokenizer: &mut Tokenizer, code: Code, size_open: usize) -> StateFnResult {
+    match code {
+        Code::None => (State::Nok, None),
+        Code::Char('$') => {
+            tokenizer.exit(TokenType::MathTextData);
+            tokenizer.enter(TokenType::MathTextSequence);
+            sequence_close(tokenizer, code, size_open, 0)
+        }
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| data(t, c, size_open))),
+                None,
+            )
+        }
+    }
+}
+
+/// In a candidate closing sequence.
+fn sequence_close(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    size_open: usize,
+    size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('$') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    sequence_close(t, c, size_open, size + 1)
+                })),
+                None,
+            )
+        }
+        // A run of the same length as the opening sequence: that’s the
+        // close.
+        _ if size == size_open => {
+            tokenizer.exit(TokenType::MathTextSequence);
+            tokenizer.exit(TokenType::MathText);
+            (State::Ok, None)
+        }
+        // A run of a different length: it was data (or a shorter/longer
+        // marker run nested in content), keep scanning for data and/or a
+        // proper close.
+        _ => {
+            tokenizer.exit(TokenType::MathTextSequence);
+            tokenizer.enter(TokenType::MathTextData);
+            data(tokenizer, code, size_open)
+        }
+    }
+}
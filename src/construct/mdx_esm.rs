@@ -0,0 +1,108 @@
+//! The MDX ESM construct.
+//!
+//! MDX ESM is `import`/`export` statements at the top level of a
+//! document, such as:
+//!
+//! ```markdown
+//! import Chart from './chart.js'
+//!
+//! # Sales
+//! ```
+//!
+//! Unlike most flow constructs, it is only recognized unprefixed (it
+//! cannot be indented), same as code (indented): see `initial_before` in
+//! [flow][crate::content::flow].
+//!
+//! A statement is taken to continue for as long as lines keep coming
+//! (blank lines end it), rather than actually parsing JavaScript/TypeScript
+//! to find where the statement balances.
+//!
+//! ## References
+//!
+//! * [`micromark-extension-mdxjs-esm`](https://github.com/micromark/micromark-extension-mdxjs-esm)
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Start of MDX ESM.
+///
+/// ```markdown
+/// |import a from 'b'
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('i' | 'e') => {
+            let keyword = if matches!(code, Code::Char('i')) {
+                "import"
+            } else {
+                "export"
+            };
+            tokenizer.enter(TokenType::MdxjsEsm);
+            tokenizer.enter(TokenType::MdxjsEsmData);
+            keyword_inside(tokenizer, code, keyword, 0)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// In `import` or `export` (`keyword`, picked once from the first
+/// character): checking that its full, exact, literal spelling is used,
+/// followed by whitespace.
+fn keyword_inside(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    keyword: &'static str,
+    index: usize,
+) -> StateFnResult {
+    let matches_next_byte = keyword.as_bytes().get(index).map_or(false, |byte| {
+        matches!(code, Code::Char(char) if char == *byte as char)
+    });
+
+    match code {
+        _ if matches_next_byte => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| keyword_inside(t, c, keyword, index + 1))),
+                None,
+            )
+        }
+        Code::Char(' ' | '\t') if index == keyword.len() => line_rest(tokenizer, code),
+        _ => (State::Nok, None),
+    }
+}
+
+/// The rest of a line, once we know it is a valid ESM keyword line.
+fn line_rest(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::None => {
+            tokenizer.exit(TokenType::MdxjsEsmData);
+            tokenizer.exit(TokenType::MdxjsEsm);
+            (State::Ok, None)
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::MdxjsEsmData);
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (State::Fn(Box::new(line_start)), None)
+        }
+        _ => {
+            tokenizer.consume(code);
+            (State::Fn(Box::new(line_rest)), None)
+        }
+    }
+}
+
+/// At the start of a later line: a blank line ends the statement,
+/// anything else continues it.
+fn line_start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::None | Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::MdxjsEsm);
+            (State::Ok, None)
+        }
+        _ => {
+            tokenizer.enter(TokenType::MdxjsEsmData);
+            line_rest(tokenizer, code)
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! The MDX expression (flow) construct.
+//!
+//! MDX flow expressions are a `{`/`}` pair, on their own (optionally
+//! indented ≤3 spaces), that may span multiple lines:
+//!
+//! ```markdown
+//! {
+//!   1 + 1
+//! }
+//! ```
+//!
+//! Unlike the JavaScript it contains, this construct does not parse the
+//! expression: it only tracks brace balance to find where the expression
+//! ends. Braces written inside a string are counted like any other brace.
+//!
+//! ## References
+//!
+//! * [`micromark-extension-mdx-expression-flow`](https://github.com/micromark/micromark-extension-mdx-expression-flow)
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Start of an MDX flow expression.
+///
+/// ```markdown
+/// |{1 + 1}
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('{') => {
+            tokenizer.enter(TokenType::MdxFlowExpression);
+            tokenizer.enter(TokenType::MdxExpressionMarker);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxExpressionMarker);
+            (State::Fn(Box::new(|t, c| data_or_close(t, c, 1))), None)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// Inside (or at the end of) the expression, tracking brace depth.
+///
+/// `depth` is the number of unmatched `{` seen so far (always ≥ 1 while
+/// inside the construct).
+fn data_or_close(tokenizer: &mut Tokenizer, code: Code, depth: usize) -> StateFnResult {
+    match code {
+        // An unbalanced expression runs to the end of the document: not a
+        // valid flow expression.
+        Code::None => (State::Nok, None),
+        Code::Char('{') => {
+            tokenizer.enter(TokenType::MdxExpressionData);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxExpressionData);
+            (
+                State::Fn(Box::new(move |t, c| data_or_close(t, c, depth + 1))),
+                None,
+            )
+        }
+        Code::Char('}') if depth == 1 => {
+            tokenizer.enter(TokenType::MdxExpressionMarker);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxExpressionMarker);
+            (State::Fn(Box::new(after)), None)
+        }
+        Code::Char('}') => {
+            tokenizer.enter(TokenType::MdxExpressionData);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxExpressionData);
+            (
+                State::Fn(Box::new(move |t, c| data_or_close(t, c, depth - 1))),
+                None,
+            )
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (
+                State::Fn(Box::new(move |t, c| data_or_close(t, c, depth))),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.enter(TokenType::MdxExpressionData);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxExpressionData);
+            (
+                State::Fn(Box::new(move |t, c| data_or_close(t, c, depth))),
+                None,
+            )
+        }
+    }
+}
+
+/// After the closing `}`: only whitespace and an eol or eof are allowed,
+/// same as other flow constructs.
+fn after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (State::Fn(Box::new(after)), None)
+        }
+        Code::None | Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(TokenType::MdxFlowExpression);
+            (State::Ok, None)
+        }
+        _ => (State::Nok, None),
+    }
+}
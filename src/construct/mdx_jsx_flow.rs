@@ -0,0 +1,321 @@
+//! The MDX JSX (flow) construct.
+//!
+//! MDX JSX flow elements are JSX, on their own line(s):
+//!
+//! ```markdown
+//! <Chart data={sales} />
+//!
+//! <Box>
+//!   <Chart data={sales} />
+//! </Box>
+//! ```
+//!
+//! This does not parse JSX attribute values or children as JSX/JS (that
+//! would need a full JSX/JS parser): it tracks quotes and `{`/`}` balance
+//! so `>` inside an attribute value doesn’t end the tag early, and it
+//! tracks nesting of elements with the *same* tag name to find the
+//! matching closing tag, same as `micromark-extension-mdx-jsx` does at
+//! the micromark level (a later, separate, step turns this into an actual
+//! JSX AST).
+//!
+//! ## References
+//!
+//! * [`micromark-extension-mdx-jsx`](https://github.com/micromark/micromark-extension-mdx-jsx)
+
+use alloc::string::String;
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Start of an MDX JSX flow element.
+///
+/// ```markdown
+/// |<Box>
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('<') => {
+            tokenizer.enter(TokenType::MdxJsxFlowElement);
+            tokenizer.enter(TokenType::MdxJsxTagMarker);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxJsxTagMarker);
+            (
+                State::Fn(Box::new(|t, c| tag_name_start(t, c))),
+                None,
+            )
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// Whether `char` may appear in a (possibly namespaced/member) tag name.
+fn is_name_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || matches!(char, '.' | '-' | ':' | '_')
+}
+
+/// Start of the opening tag’s name.
+fn tag_name_start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char(char) if char.is_ascii_alphabetic() => {
+            tokenizer.enter(TokenType::MdxJsxTagName);
+            tag_name_inside(tokenizer, code, String::new())
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// Inside the opening tag’s name.
+fn tag_name_inside(tokenizer: &mut Tokenizer, code: Code, mut name: String) -> StateFnResult {
+    match code {
+        Code::Char(char) if is_name_char(char) => {
+            name.push(char);
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| tag_name_inside(t, c, name))),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.exit(TokenType::MdxJsxTagName);
+            attributes(tokenizer, code, name, None, 0)
+        }
+    }
+}
+
+/// In the attributes of the opening tag.
+///
+/// `quote` is `Some` while inside a quoted attribute value; `brace_depth`
+/// is the unmatched `{` count while inside an expression attribute value.
+fn attributes(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    name: String,
+    quote: Option<char>,
+    brace_depth: usize,
+) -> StateFnResult {
+    match code {
+        Code::None => (State::Nok, None),
+        Code::Char(char) if quote == Some(char) => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| attributes(t, c, name, None, brace_depth))),
+                None,
+            )
+        }
+        _ if quote.is_some() => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| attributes(t, c, name, quote, brace_depth))),
+                None,
+            )
+        }
+        // Quotes are tracked before brace balance, and regardless of it:
+        // a `}` written inside a string inside an expression (e.g.
+        // `x={"}"}`) must not be mistaken for the expression's closing
+        // brace.
+        Code::Char('"' | '\'') => {
+            let marker = if let Code::Char(char) = code { char } else { unreachable!() };
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    attributes(t, c, name, Some(marker), brace_depth)
+                })),
+                None,
+            )
+        }
+        Code::Char('{') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    attributes(t, c, name, quote, brace_depth + 1)
+                })),
+                None,
+            )
+        }
+        Code::Char('}') if brace_depth > 0 => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    attributes(t, c, name, quote, brace_depth - 1)
+                })),
+                None,
+            )
+        }
+        _ if brace_depth > 0 => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| attributes(t, c, name, quote, brace_depth))),
+                None,
+            )
+        }
+        Code::Char('/') => {
+            tokenizer.enter(TokenType::MdxJsxTagMarker);
+            tokenizer.consume(code);
+            (State::Fn(Box::new(move |t, c| self_closing_slash(t, c, name))), None)
+        }
+        Code::Char('>') => {
+            tokenizer.enter(TokenType::MdxJsxTagMarker);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxJsxTagMarker);
+            tokenizer.enter(TokenType::MdxJsxFlowElementChildren);
+            (State::Fn(Box::new(move |t, c| children(t, c, name, 1))), None)
+        }
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| attributes(t, c, name, quote, brace_depth))),
+                None,
+            )
+        }
+    }
+}
+
+/// After a `/` while looking for the end of the opening tag: only a
+/// self-closing `/>` is valid here.
+fn self_closing_slash(tokenizer: &mut Tokenizer, code: Code, name: String) -> StateFnResult {
+    match code {
+        Code::Char('>') => {
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxJsxTagMarker);
+            tokenizer.exit(TokenType::MdxJsxFlowElement);
+            let _ = name;
+            (State::Fn(Box::new(after)), None)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// After a fully closed element (self-closing, or its matching closing
+/// tag): only whitespace and an eol or eof are allowed, same as other
+/// flow constructs.
+fn after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (State::Fn(Box::new(after)), None)
+        }
+        Code::None | Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => (State::Ok, None),
+        _ => (State::Nok, None),
+    }
+}
+
+/// In the element’s children, looking for a nested element with the same
+/// name (to track `depth`), or the matching closing tag (at `depth == 1`).
+fn children(tokenizer: &mut Tokenizer, code: Code, name: String, depth: usize) -> StateFnResult {
+    match code {
+        // No matching closing tag before the end of the document.
+        Code::None => (State::Nok, None),
+        Code::Char('<') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| after_lt(t, c, name, depth))),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| children(t, c, name, depth))),
+                None,
+            )
+        }
+    }
+}
+
+/// Right after a `<` in the children: either a closing tag (`/`), or
+/// another (possibly nested) opening tag.
+fn after_lt(tokenizer: &mut Tokenizer, code: Code, name: String, depth: usize) -> StateFnResult {
+    match code {
+        Code::Char('/') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    candidate_name(t, c, name, depth, true, String::new())
+                })),
+                None,
+            )
+        }
+        _ => candidate_name(tokenizer, code, name, depth, false, String::new()),
+    }
+}
+
+/// Collecting the name of a candidate (opening or closing) tag found
+/// inside the children, to compare it against the element’s own name.
+fn candidate_name(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    name: String,
+    depth: usize,
+    closing: bool,
+    mut candidate: String,
+) -> StateFnResult {
+    match code {
+        Code::Char(char) if is_name_char(char) => {
+            candidate.push(char);
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    candidate_name(t, c, name, depth, closing, candidate)
+                })),
+                None,
+            )
+        }
+        // A different tag (or plain text that happened to contain `<`):
+        // not interesting for nesting, keep scanning as ordinary children.
+        _ if candidate != name => children(tokenizer, code, name, depth),
+        // Our own tag, opening, and immediately self-closing (`<Name/>`):
+        // check for the `/>` shorthand before assuming it deepens nesting.
+        Code::Char('/') if !closing => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| candidate_self_closing(t, c, name, depth))),
+                None,
+            )
+        }
+        // Our own tag, opening: one level deeper.
+        _ if !closing => children(tokenizer, code, name, depth + 1),
+        // Our own tag, closing, and this was the outermost one: done.
+        _ if depth == 1 => {
+            tokenizer.enter(TokenType::MdxJsxTagMarker);
+            closing_tag_end(tokenizer, code)
+        }
+        // Our own tag, closing, but a nested one: one level shallower.
+        _ => children(tokenizer, code, name, depth - 1),
+    }
+}
+
+/// Right after `<Name/` for a same-named candidate: if this really is the
+/// self-closing shorthand, it cancels out and nesting is unchanged;
+/// otherwise, fall back to treating it as a (non-self-closing) opening
+/// tag a level deeper.
+fn candidate_self_closing(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    name: String,
+    depth: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('>') => {
+            tokenizer.consume(code);
+            (State::Fn(Box::new(move |t, c| children(t, c, name, depth))), None)
+        }
+        _ => children(tokenizer, code, name, depth + 1),
+    }
+}
+
+/// Skipping whitespace up to the closing tag’s final `>`.
+fn closing_tag_end(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (State::Fn(Box::new(closing_tag_end)), None)
+        }
+        Code::Char('>') => {
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::MdxJsxTagMarker);
+            tokenizer.exit(TokenType::MdxJsxFlowElementChildren);
+            tokenizer.exit(TokenType::MdxJsxFlowElement);
+            (State::Fn(Box::new(after)), None)
+        }
+        _ => (State::Nok, None),
+    }
+}
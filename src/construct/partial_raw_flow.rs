@@ -0,0 +1,252 @@
+//! A partial for flow constructs shaped like “fenced code”: an opening
+//! fence, a run of raw lines, and a closing fence whose marker and length
+//! must match (or exceed) the opening one.
+//!
+//! [Math (flow)][crate::construct::math_flow] is built on this partial: it
+//! does not look at its content as markdown, and closes either on a
+//! matching fence or, if none is found, at the end of the document.
+//! [Code (fenced)][crate::construct::code_fenced] has the same shape, but
+//! does not use this partial.
+//!
+//! The grammar is:
+//!
+//! ```bnf
+//! raw_flow ::= fence *space_or_tab [eol *line [closing_fence *space_or_tab]]
+//! fence ::= min_size*marker_char
+//! closing_fence ::= min_size*marker_char
+//! ```
+//!
+//! where `min_size` is [`Parts::min_size`], and `closing_fence` must use
+//! the same `marker_char` as `fence`, and must be at least as long.
+//!
+//! Unlike code (fenced), this partial does not support an info string:
+//! anything but whitespace after the opening sequence means the line is
+//! not raw flow.
+
+use crate::tokenizer::{Code, State, StateFnResult, TokenType, Tokenizer};
+
+/// Configuration for one raw flow construct.
+#[derive(Debug, Clone, Copy)]
+pub struct Parts {
+    /// The character the fence is made of (e.g. `` ` `` or `$`).
+    pub marker: char,
+    /// The smallest allowed size of a fence.
+    pub min_size: usize,
+    /// Token for the whole construct.
+    pub whole: TokenType,
+    /// Token for a fence (opening or closing), containing [`Parts::sequence`].
+    pub fence: TokenType,
+    /// Token for the run of marker characters in a fence.
+    pub sequence: TokenType,
+    /// Token for a line of raw content.
+    pub chunk: TokenType,
+}
+
+/// Start of raw flow, at the first marker character of the opening fence.
+///
+/// ```markdown
+/// |$$
+/// x = y
+/// $$
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code, parts: Parts) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == parts.marker => {
+            tokenizer.enter(parts.whole);
+            tokenizer.enter(parts.fence);
+            tokenizer.enter(parts.sequence);
+            sequence_open(tokenizer, code, parts, 0)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// In the opening fence sequence.
+fn sequence_open(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    parts: Parts,
+    size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == parts.marker => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| sequence_open(t, c, parts, size + 1))),
+                None,
+            )
+        }
+        _ if size >= parts.min_size => {
+            tokenizer.exit(parts.sequence);
+            tokenizer.exit(parts.fence);
+            fence_end(tokenizer, code, parts, size)
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// At the end of the opening fence line: only whitespace and an eol or
+/// eof are allowed.
+fn fence_end(tokenizer: &mut Tokenizer, code: Code, parts: Parts, opening_size: usize) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| fence_end(t, c, parts, opening_size))),
+                None,
+            )
+        }
+        Code::None => {
+            tokenizer.exit(parts.whole);
+            (State::Ok, None)
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (
+                State::Fn(Box::new(move |t, c| content_line_start(t, c, parts, opening_size))),
+                None,
+            )
+        }
+        _ => (State::Nok, None),
+    }
+}
+
+/// At the start of a line in the raw body: either the closing fence, or
+/// another line of raw content.
+///
+/// `opening_size` is how many marker characters the opening fence had: a
+/// candidate closing fence must reach at least that size.
+fn content_line_start(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    parts: Parts,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::None => {
+            // No closing fence: the raw flow still ends successfully, it
+            // simply runs to the end of the document.
+            tokenizer.exit(parts.whole);
+            (State::Ok, None)
+        }
+        Code::Char(char) if char == parts.marker => {
+            tokenizer.enter(parts.fence);
+            tokenizer.enter(parts.sequence);
+            closing_sequence(tokenizer, code, parts, opening_size, 0)
+        }
+        _ => {
+            tokenizer.enter(parts.chunk);
+            content_line_inside(tokenizer, code, parts, opening_size)
+        }
+    }
+}
+
+/// Inside a raw content line.
+fn content_line_inside(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    parts: Parts,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::None => {
+            tokenizer.exit(parts.chunk);
+            content_line_start(tokenizer, code, parts, opening_size)
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.exit(parts.chunk);
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    content_line_start(t, c, parts, opening_size)
+                })),
+                None,
+            )
+        }
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    content_line_inside(t, c, parts, opening_size)
+                })),
+                None,
+            )
+        }
+    }
+}
+
+/// In a candidate closing fence sequence.
+fn closing_sequence(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    parts: Parts,
+    opening_size: usize,
+    size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char(char) if char == parts.marker => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    closing_sequence(t, c, parts, opening_size, size + 1)
+                })),
+                None,
+            )
+        }
+        // Long enough: this is the closing fence.
+        _ if size >= parts.min_size && size >= opening_size => {
+            tokenizer.exit(parts.sequence);
+            tokenizer.exit(parts.fence);
+            closing_fence_end(tokenizer, code, parts, opening_size)
+        }
+        // Too short: the whole line, sequence included, is raw content.
+        _ => {
+            tokenizer.exit(parts.sequence);
+            tokenizer.exit(parts.fence);
+            tokenizer.enter(parts.chunk);
+            content_line_inside(tokenizer, code, parts, opening_size)
+        }
+    }
+}
+
+/// After a (long enough) closing sequence: only whitespace and an eol or
+/// eof are allowed.
+fn closing_fence_end(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    parts: Parts,
+    opening_size: usize,
+) -> StateFnResult {
+    match code {
+        Code::Char('\t' | ' ') => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| {
+                    closing_fence_end(t, c, parts, opening_size)
+                })),
+                None,
+            )
+        }
+        Code::None => {
+            tokenizer.exit(parts.whole);
+            (State::Ok, None)
+        }
+        Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
+            tokenizer.enter(TokenType::LineEnding);
+            tokenizer.consume(code);
+            tokenizer.exit(TokenType::LineEnding);
+            tokenizer.exit(parts.whole);
+            (State::Ok, None)
+        }
+        // Trailing content: not a valid close, keep treating lines as raw
+        // content instead.
+        _ => {
+            tokenizer.enter(parts.chunk);
+            content_line_inside(tokenizer, code, parts, opening_size)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Which constructs are enabled.
+//!
+//! Some constructs are not part of `CommonMark` and must be explicitly
+//! turned on by embedders (they default to off).
+
+/// Configuration that turns optional, non-`CommonMark`, constructs on or
+/// off.
+#[derive(Debug, Clone, Copy)]
+pub struct Constructs {
+    /// Whether to support frontmatter (YAML or TOML).
+    ///
+    /// ```markdown
+    /// ---
+    /// title: Neptune
+    /// ---
+    ///
+    /// # Neptune
+    /// ```
+    pub frontmatter: bool,
+    /// Whether to support math (flow), such as `$$\nx\n$$`.
+    pub math_flow: bool,
+    /// Whether to support math (text), such as `$x$`.
+    pub math_text: bool,
+    /// Whether to support MDX: ESM (`import`/`export`), flow expressions
+    /// (`{1 + 1}`), and JSX (`<Chart />`).
+    pub mdx: bool,
+}
+
+impl Default for Constructs {
+    /// `CommonMark` only, so frontmatter, math, and MDX are off.
+    fn default() -> Self {
+        Self {
+            frontmatter: false,
+            math_flow: false,
+            math_text: false,
+            mdx: false,
+        }
+    }
+}
@@ -14,9 +14,14 @@
 //! *   [Code (fenced)][crate::construct::code_fenced]
 //! *   [Code (indented)][crate::construct::code_indented]
 //! *   [Definition][crate::construct::definition]
+//! *   [Frontmatter][crate::construct::frontmatter]
 //! *   [Heading (atx)][crate::construct::heading_atx]
 //! *   [Heading (setext)][crate::construct::heading_setext]
 //! *   [HTML (flow)][crate::construct::html_flow]
+//! *   [Math (flow)][crate::construct::math_flow]
+//! *   [MDX ESM][crate::construct::mdx_esm]
+//! *   [MDX expression (flow)][crate::construct::mdx_expression_flow]
+//! *   [MDX JSX (flow)][crate::construct::mdx_jsx_flow]
 //! *   [Thematic break][crate::construct::thematic_break]
 //!
 //! <!-- To do: Link to content. -->
@@ -25,18 +30,27 @@ use crate::constant::TAB_SIZE;
 use crate::construct::{
     blank_line::start as blank_line, code_fenced::start as code_fenced,
     code_indented::start as code_indented, definition::start as definition,
-    heading_atx::start as heading_atx, heading_setext::start as heading_setext,
-    html_flow::start as html_flow, partial_whitespace::start as whitespace,
-    thematic_break::start as thematic_break,
+    frontmatter::start as frontmatter, heading_atx::start as heading_atx,
+    heading_setext::start as heading_setext, html_flow::start as html_flow,
+    math_flow::start as math_flow, mdx_esm::start as mdx_esm,
+    mdx_expression_flow::start as mdx_expression_flow, mdx_jsx_flow::start as mdx_jsx_flow,
+    partial_whitespace::start as whitespace, thematic_break::start as thematic_break,
 };
+use crate::constructs::Constructs;
 use crate::subtokenize::subtokenize;
 use crate::tokenizer::{Code, Event, Point, State, StateFnResult, TokenType, Tokenizer};
 use crate::util::span::from_exit_event;
 
 /// Turn `codes` as the flow content type into events.
-pub fn flow(codes: &[Code], point: Point, index: usize) -> Vec<Event> {
+pub fn flow(codes: &[Code], point: Point, index: usize, constructs: &Constructs) -> Vec<Event> {
     let mut tokenizer = Tokenizer::new(point, index);
-    tokenizer.feed(codes, Box::new(start), true);
+    let constructs = *constructs;
+    let entry: Box<dyn Fn(&mut Tokenizer, Code) -> StateFnResult> = if constructs.frontmatter {
+        Box::new(move |t, c| start_with_frontmatter(t, c, constructs))
+    } else {
+        Box::new(move |t, c| start(t, c, constructs))
+    };
+    tokenizer.feed(codes, entry, true);
     let mut result = (tokenizer.events, false);
     while !result.1 {
         result = subtokenize(result.0, codes);
@@ -44,6 +58,26 @@ pub fn flow(codes: &[Code], point: Point, index: usize) -> Vec<Event> {
     result.0
 }
 
+/// Before flow, when frontmatter is turned on.
+///
+/// Frontmatter is only ever recognized once, right at the start of the
+/// document, so this is only ever used as the very first state, never
+/// re-entered by [`blank_line_after`] or inside a container.
+///
+/// ```markdown
+/// |---
+/// |title: Neptune
+/// |---
+/// ```
+fn start_with_frontmatter(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    match code {
+        Code::None => (State::Ok, None),
+        _ => tokenizer.attempt(frontmatter, move |_ok| {
+            Box::new(move |t, c| start(t, c, constructs))
+        })(tokenizer, code),
+    }
+}
+
 /// Before flow.
 ///
 /// First we assume a blank line.
@@ -54,11 +88,17 @@ pub fn flow(codes: &[Code], point: Point, index: usize) -> Vec<Event> {
 /// |    bravo
 /// |***
 /// ```
-pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+pub fn start(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     match code {
         Code::None => (State::Ok, None),
-        _ => tokenizer.attempt(blank_line, |ok| {
-            Box::new(if ok { blank_line_after } else { initial_before })
+        _ => tokenizer.attempt(blank_line, move |ok| {
+            Box::new(move |t, c| {
+                if ok {
+                    blank_line_after(t, c, constructs)
+                } else {
+                    initial_before(t, c, constructs)
+                }
+            })
         })(tokenizer, code),
     }
 }
@@ -70,14 +110,17 @@ pub fn start(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// ```markdown
 /// ␠␠|
 /// ```
-fn blank_line_after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+fn blank_line_after(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     match code {
         Code::None => (State::Ok, None),
         Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
             tokenizer.enter(TokenType::BlankLineEnding);
             tokenizer.consume(code);
             tokenizer.exit(TokenType::BlankLineEnding);
-            (State::Fn(Box::new(start)), None)
+            (
+                State::Fn(Box::new(move |t, c| start(t, c, constructs))),
+                None,
+            )
         }
         _ => unreachable!("expected eol/eof after blank line `{:?}`", code),
     }
@@ -95,12 +138,29 @@ fn blank_line_after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// |~~~js
 /// |<div>
 /// ```
-fn initial_before(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+fn initial_before(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     match code {
         Code::None => (State::Ok, None),
         // To do: should all flow just start before the prefix?
-        _ => tokenizer.attempt_3(code_indented, code_fenced, html_flow, |ok| {
-            Box::new(if ok { after } else { before })
+        _ if constructs.mdx => {
+            tokenizer.attempt_4(code_indented, code_fenced, html_flow, mdx_esm, move |ok| {
+                Box::new(move |t, c| {
+                    if ok {
+                        after(t, c, constructs)
+                    } else {
+                        before(t, c, constructs)
+                    }
+                })
+            })(tokenizer, code)
+        }
+        _ => tokenizer.attempt_3(code_indented, code_fenced, html_flow, move |ok| {
+            Box::new(move |t, c| {
+                if ok {
+                    after(t, c, constructs)
+                } else {
+                    before(t, c, constructs)
+                }
+            })
         })(tokenizer, code),
     }
 }
@@ -114,14 +174,17 @@ fn initial_before(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// asd
 /// ~~~|
 /// ```
-fn after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+fn after(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     match code {
         Code::None => (State::Ok, None),
         Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
             tokenizer.enter(TokenType::LineEnding);
             tokenizer.consume(code);
             tokenizer.exit(TokenType::LineEnding);
-            (State::Fn(Box::new(start)), None)
+            (
+                State::Fn(Box::new(move |t, c| start(t, c, constructs))),
+                None,
+            )
         }
         _ => unreachable!("unexpected non-eol/eof after flow `{:?}`", code),
     }
@@ -134,10 +197,10 @@ fn after(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// ```markdown
 /// |qwe
 /// ```
-pub fn before(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+pub fn before(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     tokenizer.attempt(
         |tokenizer, code| whitespace(tokenizer, code, TokenType::Whitespace),
-        |_ok| Box::new(before_after_prefix),
+        move |_ok| Box::new(move |t, c| before_after_prefix(t, c, constructs)),
     )(tokenizer, code)
 }
 
@@ -147,51 +210,102 @@ pub fn before(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
 /// |# asd
 /// |***
 /// ```
-pub fn before_after_prefix(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
-    tokenizer.attempt_4(
-        heading_atx,
-        thematic_break,
-        definition,
-        heading_setext,
-        |ok| Box::new(if ok { after } else { content_before }),
-    )(tokenizer, code)
+pub fn before_after_prefix(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    if constructs.mdx {
+        return tokenizer.attempt_2(mdx_expression_flow, mdx_jsx_flow, move |ok| {
+            Box::new(move |t, c| {
+                if ok {
+                    after(t, c, constructs)
+                } else {
+                    before_after_prefix_math(t, c, constructs)
+                }
+            })
+        })(tokenizer, code);
+    }
+
+    before_after_prefix_math(tokenizer, code, constructs)
+}
+
+/// Before flow, after potential whitespace and (if turned on) MDX
+/// expression/JSX.
+fn before_after_prefix_math(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    if constructs.math_flow {
+        tokenizer.attempt(math_flow, move |ok| {
+            Box::new(move |t, c| {
+                if ok {
+                    after(t, c, constructs)
+                } else {
+                    before_after_prefix_commonmark(t, c, constructs)
+                }
+            })
+        })(tokenizer, code)
+    } else {
+        before_after_prefix_commonmark(tokenizer, code, constructs)
+    }
+}
+
+/// Before flow, after potential whitespace and (if turned on) math (flow).
+fn before_after_prefix_commonmark(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    constructs: Constructs,
+) -> StateFnResult {
+    tokenizer.attempt_4(heading_atx, thematic_break, definition, heading_setext, move |ok| {
+        Box::new(move |t, c| {
+            if ok {
+                after(t, c, constructs)
+            } else {
+                content_before(t, c, constructs)
+            }
+        })
+    })(tokenizer, code)
 }
 
-/// Before content.
+/// Before content, now that `before_after_prefix_commonmark` has already
+/// tried (and failed at) a definition here: only a blank line or a
+/// paragraph are left.
+///
+/// A later definition can still interrupt and get taken out of the
+/// content stream: see `continuation_construct_after_prefix`.
 ///
 /// ```markdown
 /// |qwe
 /// ```
-///
-// To do: we don’t need content anymore in `micromark-rs` it seems?
-fn content_before(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+fn content_before(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     match code {
         Code::None | Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
-            after(tokenizer, code)
-        }
-        _ => {
-            tokenizer.enter(TokenType::Content);
-            tokenizer.enter(TokenType::ChunkContent);
-            content(tokenizer, code, tokenizer.events.len() - 1)
+            after(tokenizer, code, constructs)
         }
+        _ => content_paragraph_before(tokenizer, code, constructs),
     }
 }
 
+/// Before a paragraph, now that a line didn’t start with a definition.
+///
+/// ```markdown
+/// |qwe
+/// ```
+fn content_paragraph_before(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    tokenizer.enter(TokenType::Content);
+    tokenizer.enter(TokenType::ChunkContent);
+    content(tokenizer, code, tokenizer.events.len() - 1, constructs)
+}
+
 /// In content.
 ///
 /// ```markdown
 /// al|pha
 /// ```
-fn content(tokenizer: &mut Tokenizer, code: Code, previous: usize) -> StateFnResult {
+fn content(tokenizer: &mut Tokenizer, code: Code, previous: usize, constructs: Constructs) -> StateFnResult {
     match code {
-        Code::None => content_end(tokenizer, code),
+        Code::None => content_end(tokenizer, code, constructs),
         Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => {
             tokenizer.check(continuation_construct, move |ok| {
                 Box::new(move |t, c| {
                     if ok {
-                        content_continue(t, c, previous)
+                        content_continue(t, c, previous, constructs)
                     } else {
-                        content_end(t, c)
+                        content_end(t, c, constructs)
                     }
                 })
             })(tokenizer, code)
@@ -199,7 +313,7 @@ fn content(tokenizer: &mut Tokenizer, code: Code, previous: usize) -> StateFnRes
         _ => {
             tokenizer.consume(code);
             (
-                State::Fn(Box::new(move |t, c| content(t, c, previous))),
+                State::Fn(Box::new(move |t, c| content(t, c, previous, constructs))),
                 None,
             )
         }
@@ -252,8 +366,10 @@ fn continuation_construct_after_prefix(tokenizer: &mut Tokenizer, code: Code) ->
         Code::None | Code::CarriageReturnLineFeed | Code::Char('\n' | '\r') => (State::Nok, None),
         // To do: If code is disabled, indented lines are part of the content.
         _ if prefix >= TAB_SIZE => (State::Ok, None),
-        // To do: definitions, setext headings, etc?
-        _ => tokenizer.attempt_2(heading_atx, thematic_break, |ok| {
+        // To do: setext headings, etc?
+        // A definition here also interrupts the paragraph:
+        // `before_after_prefix_commonmark` then picks it up as a definition.
+        _ => tokenizer.attempt_3(heading_atx, thematic_break, definition, |ok| {
             let result = if ok {
                 (State::Nok, None)
             } else {
@@ -264,7 +380,12 @@ fn continuation_construct_after_prefix(tokenizer: &mut Tokenizer, code: Code) ->
     }
 }
 
-fn content_continue(tokenizer: &mut Tokenizer, code: Code, previous_index: usize) -> StateFnResult {
+fn content_continue(
+    tokenizer: &mut Tokenizer,
+    code: Code,
+    previous_index: usize,
+    constructs: Constructs,
+) -> StateFnResult {
     tokenizer.consume(code);
     tokenizer.exit(TokenType::ChunkContent);
     tokenizer.enter(TokenType::ChunkContent);
@@ -272,13 +393,13 @@ fn content_continue(tokenizer: &mut Tokenizer, code: Code, previous_index: usize
     tokenizer.events[previous_index].next = Some(next_index);
     tokenizer.events[next_index].previous = Some(previous_index);
     (
-        State::Fn(Box::new(move |t, c| content(t, c, next_index))),
+        State::Fn(Box::new(move |t, c| content(t, c, next_index, constructs))),
         None,
     )
 }
 
-fn content_end(tokenizer: &mut Tokenizer, code: Code) -> StateFnResult {
+fn content_end(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
     tokenizer.exit(TokenType::ChunkContent);
     tokenizer.exit(TokenType::Content);
-    after(tokenizer, code)
+    after(tokenizer, code, constructs)
 }
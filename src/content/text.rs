@@ -0,0 +1,59 @@
+//! The text content type.
+//!
+//! **Text** represents the sections, such as emphasis and links, which
+//! occur inside other constructs, on a single line at a time: it is
+//! parsed per line, same as flow.
+//!
+//! The constructs found in text are:
+//!
+//! *   [Math (text)][crate::construct::math_text]
+//!
+//! <!-- To do: Link to other text constructs (code (text), emphasis,
+//! links, ...) once they exist. -->
+
+use crate::construct::math_text::start as math_text;
+use crate::constructs::Constructs;
+use crate::tokenizer::{Code, State, StateFnResult, Tokenizer};
+
+/// Start of text.
+///
+/// ```markdown
+/// |qwe
+/// ```
+pub fn start(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    data_before(tokenizer, code, constructs)
+}
+
+/// Before data, at a construct, or at the end of the line.
+fn data_before(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    match code {
+        Code::None => (State::Ok, None),
+        Code::Char('$') if constructs.math_text => {
+            tokenizer.attempt(math_text, move |ok| {
+                Box::new(move |t, c| {
+                    if ok {
+                        data_before(t, c, constructs)
+                    } else {
+                        data(t, c, constructs)
+                    }
+                })
+            })(tokenizer, code)
+        }
+        _ => data(tokenizer, code, constructs),
+    }
+}
+
+/// In data.
+fn data(tokenizer: &mut Tokenizer, code: Code, constructs: Constructs) -> StateFnResult {
+    match code {
+        Code::None => (State::Ok, None),
+        Code::Char('$') if constructs.math_text => data_before(tokenizer, code, constructs),
+        _ => {
+            tokenizer.consume(code);
+            (
+                State::Fn(Box::new(move |t, c| data(t, c, constructs))),
+                None,
+            )
+        }
+    }
+}
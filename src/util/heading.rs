@@ -0,0 +1,89 @@
+//! Helpers for embedders that want rustdoc-style heading handling:
+//! shifting heading ranks, assigning unique heading `id`s (see
+//! [`IdMap`][super::slug::IdMap]), and building a table of contents from
+//! a document-order list of headings.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Shift a heading `rank` (1–6, as in `h1`–`h6`) by `offset`, clamping the
+/// result back into the 1–6 range.
+///
+/// Used to let embedders nest a document’s headings under a page’s own
+/// heading (for example, rendering a document’s `# Title` as `<h2>` when
+/// it is embedded under a page’s `<h1>`).
+pub fn apply_offset(rank: u8, offset: i8) -> u8 {
+    let shifted = i16::from(rank) + i16::from(offset);
+    shifted.clamp(1, 6) as u8
+}
+
+/// One heading, in document order, as seen by the table-of-contents
+/// builder.
+#[derive(Debug, Clone)]
+pub struct HeadingRecord {
+    /// Rank after `heading_offset` has already been applied.
+    pub rank: u8,
+    /// The heading’s unique id (see [`IdMap`][super::slug::IdMap]).
+    pub id: String,
+    /// The heading’s text content.
+    pub text: String,
+}
+
+/// A node in the nested table of contents.
+#[derive(Debug, Clone)]
+pub struct TocItem {
+    /// The heading’s unique id.
+    pub id: String,
+    /// The heading’s text content.
+    pub text: String,
+    /// Headings nested under this one (those with a greater rank, until
+    /// one with an equal or lesser rank is seen).
+    pub children: Vec<TocItem>,
+}
+
+/// Build a nested table of contents from a flat, document-order list of
+/// headings.
+///
+/// Each heading becomes a child of the closest preceding heading with a
+/// smaller rank; gaps in rank (e.g. an `h1` directly followed by an `h3`)
+/// just nest directly, same as gaps are handled by most static site
+/// generators.
+pub fn build_toc(headings: &[HeadingRecord]) -> Vec<TocItem> {
+    // One entry per currently-open rank, outermost first.
+    let mut stack: Vec<(u8, TocItem)> = Vec::new();
+    let mut roots: Vec<TocItem> = Vec::new();
+
+    for heading in headings {
+        let item = TocItem {
+            id: heading.id.clone(),
+            text: heading.text.clone(),
+            children: Vec::new(),
+        };
+
+        while let Some((rank, _)) = stack.last() {
+            if *rank < heading.rank {
+                break;
+            }
+            let (_, done) = stack.pop().unwrap();
+            attach(&mut stack, &mut roots, done);
+        }
+
+        stack.push((heading.rank, item));
+    }
+
+    while let Some((_, done)) = stack.pop() {
+        attach(&mut stack, &mut roots, done);
+    }
+
+    roots
+}
+
+/// Attach a finished item to its parent (the new top of `stack`), or to
+/// `roots` if the stack is now empty.
+fn attach(stack: &mut [(u8, TocItem)], roots: &mut Vec<TocItem>, item: TocItem) {
+    if let Some((_, parent)) = stack.last_mut() {
+        parent.children.push(item);
+    } else {
+        roots.push(item);
+    }
+}
@@ -0,0 +1,81 @@
+//! Slugs: short, URL-safe identifiers derived from text, as used for
+//! heading `id`s (see [`crate::compile_options::process_headings`] and
+//! [`IdMap`]).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Turn the text content of a heading into a slug.
+///
+/// Lowercases, replaces runs of characters that aren’t ASCII letters or
+/// digits with a single `-`, and trims leading/trailing `-`.
+///
+/// ```text
+/// slugify("Hello, World!") == "hello-world"
+/// slugify("  Étoile  ") == "toile" // non-ASCII is stripped, not transliterated
+/// ```
+pub fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    let mut last_was_hyphen = true; // swallow a leading `-`
+
+    for char in value.chars() {
+        if char.is_ascii_alphanumeric() {
+            slug.push(char.to_ascii_lowercase());
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+
+    slug
+}
+
+/// A map of ids already used on a page, so headings with the same text
+/// get unique `id`s (`a`, `a-1`, `a-2`, …).
+///
+/// One `IdMap` is meant to be reused across every heading compiled into
+/// the same page (or set of pages, such as a book), so that ids stay
+/// unique within it.
+#[derive(Debug, Default)]
+pub struct IdMap {
+    /// Every id handed out so far (not just base slugs: an explicit
+    /// `Foo-1` must also block a later `Foo` from colliding with it).
+    seen: Vec<String>,
+}
+
+impl IdMap {
+    /// Create an empty id map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn `value` into a unique id, recording it so future collisions
+    /// with the same text (or with an id this collision resolution
+    /// already produced) get `-1`, `-2`, and so on appended.
+    pub fn unique(&mut self, value: &str) -> String {
+        let slug = slugify(value);
+
+        let id = if self.seen.iter().any(|seen| *seen == slug) {
+            let mut count = 1;
+            loop {
+                let mut candidate = slug.clone();
+                candidate.push('-');
+                candidate.push_str(&count.to_string());
+                if !self.seen.iter().any(|seen| *seen == candidate) {
+                    break candidate;
+                }
+                count += 1;
+            }
+        } else {
+            slug
+        };
+
+        self.seen.push(id.clone());
+        id
+    }
+}
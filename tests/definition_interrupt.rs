@@ -0,0 +1,35 @@
+extern crate micromark;
+use micromark::micromark;
+
+#[test]
+fn definition_interrupt() {
+    assert_eq!(
+        micromark("[a]: b\nc"),
+        "<p>c</p>",
+        "should support a definition followed by a paragraph"
+    );
+
+    assert_eq!(
+        micromark("a\n[b]: c"),
+        "<p>a</p>",
+        "should let a definition interrupt a paragraph"
+    );
+
+    assert_eq!(
+        micromark("a\n[b]: c\nd"),
+        "<p>a</p>\n<p>d</p>",
+        "should let a definition interrupt a paragraph and still start a new one after it"
+    );
+
+    assert_eq!(
+        micromark("a\n[b]: c\n[d]: e\nf"),
+        "<p>a</p>\n<p>f</p>",
+        "should support more than one definition interrupting a paragraph in a row"
+    );
+
+    assert_eq!(
+        micromark("[a]\n\na\n[a]: b"),
+        "<p><a href=\"b\">a</a></p>\n<p>a</p>",
+        "should resolve a reference against a definition that interrupted a later paragraph"
+    );
+}
@@ -0,0 +1,73 @@
+extern crate micromark;
+use micromark::{micromark, micromark_with_options, Constructs, Options};
+
+#[test]
+fn frontmatter() {
+    assert_eq!(
+        micromark("---\ntitle: Neptune\n---\n\n# Neptune"),
+        "<hr />\n<h2>title: Neptune</h2>\n<h1>Neptune</h1>",
+        "should not support frontmatter by default"
+    );
+
+    let frontmatter_options = Options {
+        constructs: Constructs {
+            frontmatter: true,
+            ..Constructs::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        micromark_with_options("---\ntitle: Neptune\n---\n\n# Neptune", &frontmatter_options),
+        "<h1>Neptune</h1>",
+        "should support a yaml frontmatter when turned on"
+    );
+
+    assert_eq!(
+        micromark_with_options("+++\ntitle = \"Neptune\"\n+++\n\n# Neptune", &frontmatter_options),
+        "<h1>Neptune</h1>",
+        "should support a toml frontmatter when turned on"
+    );
+
+    assert_eq!(
+        micromark_with_options("----\ntitle: Neptune\n----\n\n# Neptune", &frontmatter_options),
+        "<h1>Neptune</h1>",
+        "should support an opening fence longer than three markers"
+    );
+
+    assert_eq!(
+        micromark_with_options("---\ntitle: Neptune\n----\n\n# Neptune", &frontmatter_options),
+        "<h1>Neptune</h1>",
+        "should support a closing fence longer than the opening fence"
+    );
+
+    assert_eq!(
+        micromark_with_options("----\ntitle: Neptune\n---\n\n# Neptune", &frontmatter_options),
+        "<hr />\n<h2>title: Neptune</h2>\n<h1>Neptune</h1>",
+        "should not close with a fence shorter than the opening fence"
+    );
+
+    assert_eq!(
+        micromark_with_options("---\ntitle: Neptune\n\n# Neptune", &frontmatter_options),
+        "<hr />\n<p>title: Neptune</p>\n<h1>Neptune</h1>",
+        "should not support frontmatter without a closing fence"
+    );
+
+    assert_eq!(
+        micromark_with_options("a\n\n---\ntitle: Neptune\n---", &frontmatter_options),
+        "<p>a</p>\n<hr />\n<h2>title: Neptune</h2>",
+        "should not support frontmatter anywhere but at the start of the document"
+    );
+
+    assert_eq!(
+        micromark_with_options("+++\ntitle = \"Neptune\"\n---", &frontmatter_options),
+        "<p>+++\ntitle = &quot;Neptune&quot;\n---</p>",
+        "should not close a toml fence with a yaml marker, or vice versa"
+    );
+
+    assert_eq!(
+        micromark_with_options("---\n- a\n- b\n---\n\n# Neptune", &frontmatter_options),
+        "<h1>Neptune</h1>",
+        "should support a yaml body line that itself starts with the marker, but too short to close"
+    );
+}
@@ -0,0 +1,65 @@
+// These exercise `apply_offset`/`IdMap`/`process_headings` directly: the
+// HTML compiler behind `micromark`/`micromark_with_options` does not call
+// any of them.
+extern crate micromark;
+use micromark::compile_options::{process_headings, HeadingOptions};
+use micromark::util::heading::apply_offset;
+use micromark::util::slug::IdMap;
+
+#[test]
+fn heading_offset() {
+    assert_eq!(apply_offset(1, 0), 1, "should leave a rank unchanged with no offset");
+    assert_eq!(apply_offset(1, 1), 2, "should shift a rank down");
+    assert_eq!(apply_offset(3, -1), 2, "should shift a rank up");
+    assert_eq!(apply_offset(6, 1), 6, "should clamp a rank at h6");
+    assert_eq!(apply_offset(1, -1), 1, "should clamp a rank at h1");
+}
+
+#[test]
+fn id_map_unique() {
+    let mut ids = IdMap::new();
+
+    assert_eq!(ids.unique("Hello World"), "hello-world", "should slugify the first occurrence");
+    assert_eq!(ids.unique("Hello World"), "hello-world-1", "should de-duplicate a repeat");
+    assert_eq!(ids.unique("Hello World"), "hello-world-2", "should keep counting up on further repeats");
+    assert_eq!(
+        ids.unique("hello-world-1"),
+        "hello-world-1-1",
+        "should not collide with an id a previous call already produced"
+    );
+}
+
+#[test]
+fn process_headings_builds_toc() {
+    let headings = vec![
+        (1u8, String::from("Intro")),
+        (2u8, String::from("Setup")),
+        (2u8, String::from("Setup")),
+        (1u8, String::from("Outro")),
+    ];
+
+    let options = HeadingOptions {
+        heading_offset: 1,
+        toc: true,
+    };
+
+    let (records, toc) = process_headings(&options, &headings);
+
+    assert_eq!(records[0].rank, 2, "should apply the heading offset to every record");
+    assert_eq!(records[0].id, "intro", "should assign the first record its plain slug");
+    assert_eq!(records[2].id, "setup-1", "should de-duplicate the repeated heading's id");
+
+    assert_eq!(toc.len(), 2, "should produce two top-level entries (Intro and Outro)");
+    assert_eq!(toc[0].children.len(), 2, "should nest both Setup headings under Intro");
+}
+
+#[test]
+fn process_headings_without_toc() {
+    let headings = vec![(1u8, String::from("Intro"))];
+    let options = HeadingOptions::default();
+
+    let (records, toc) = process_headings(&options, &headings);
+
+    assert_eq!(records[0].rank, 1, "should leave ranks unchanged with the default offset");
+    assert!(toc.is_empty(), "should not build a table of contents when toc is turned off");
+}
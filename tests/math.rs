@@ -0,0 +1,68 @@
+extern crate micromark;
+use micromark::{micromark, micromark_with_options, Constructs, Options};
+
+#[test]
+fn math() {
+    let math_options = Options {
+        constructs: Constructs {
+            math_flow: true,
+            math_text: true,
+            ..Constructs::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        micromark("$$\nx = y\n$$"),
+        "<p>$$\nx = y\n$$</p>",
+        "should not support math (flow) by default"
+    );
+
+    assert_eq!(
+        micromark_with_options("$$\nx = y\n$$", &math_options),
+        "<pre><code class=\"language-math math-display\">x = y\n</code></pre>",
+        "should support math (flow) when turned on"
+    );
+
+    assert_eq!(
+        micromark_with_options("$$$$\nx = y\n$$$$", &math_options),
+        "<pre><code class=\"language-math math-display\">x = y\n</code></pre>",
+        "should support an opening fence longer than two dollar signs"
+    );
+
+    assert_eq!(
+        micromark_with_options("$$\nx = y\n$", &math_options),
+        "<pre><code class=\"language-math math-display\">x = y\n$\n</code></pre>",
+        "should not close math (flow) with a shorter run of dollar signs"
+    );
+
+    assert_eq!(
+        micromark_with_options("$\nx = y\n$", &math_options),
+        "<p>$\nx = y\n$</p>",
+        "should not support math (flow) with a single dollar sign"
+    );
+
+    assert_eq!(
+        micromark_with_options("$x$", &math_options),
+        "<p><code class=\"language-math math-inline\">x</code></p>",
+        "should support math (text)"
+    );
+
+    assert_eq!(
+        micromark_with_options("$$ x = y $$", &math_options),
+        "<p><code class=\"language-math math-inline\">x = y</code></p>",
+        "should support math (text) with an equal-length sequence, stripping one space of padding"
+    );
+
+    assert_eq!(
+        micromark_with_options("$ x $ and $$x$$", &math_options),
+        "<p><code class=\"language-math math-inline\">x</code> and <code class=\"language-math math-inline\">x</code></p>",
+        "should support more than one math (text) span, with different sequence lengths"
+    );
+
+    assert_eq!(
+        micromark_with_options("$x$$y$", &math_options),
+        "<p><code class=\"language-math math-inline\">x</code>$y$</p>",
+        "should require a closing sequence of the same length as the opening one"
+    );
+}
@@ -0,0 +1,93 @@
+extern crate micromark;
+use micromark::{micromark, micromark_with_options, Constructs, Options};
+
+fn mdx_options() -> Options {
+    Options {
+        constructs: Constructs {
+            mdx: true,
+            ..Constructs::default()
+        },
+        ..Options::default()
+    }
+}
+
+#[test]
+fn mdx_esm() {
+    assert_eq!(
+        micromark("import a from 'b'\n\n# c"),
+        "<p>import a from 'b'</p>\n<h1>c</h1>",
+        "should not support mdx esm by default"
+    );
+
+    assert_eq!(
+        micromark_with_options("import a from 'b'\n\n# c", &mdx_options()),
+        "<h1>c</h1>",
+        "should support an import statement when mdx is turned on"
+    );
+
+    assert_eq!(
+        micromark_with_options("export const a = 1\n\n# c", &mdx_options()),
+        "<h1>c</h1>",
+        "should support an export statement"
+    );
+
+    assert_eq!(
+        micromark_with_options("importer a from 'b'", &mdx_options()),
+        "<p>importer a from 'b'</p>",
+        "should not match a keyword that is only a prefix of a longer word"
+    );
+
+    assert_eq!(
+        micromark_with_options("import a from 'b'\nimport c from 'd'\n\n# e", &mdx_options()),
+        "<h1>e</h1>",
+        "should support a statement spanning more than one line"
+    );
+}
+
+#[test]
+fn mdx_expression_flow() {
+    assert_eq!(
+        micromark_with_options("{1 + 1}", &mdx_options()),
+        "",
+        "should support an mdx flow expression"
+    );
+
+    assert_eq!(
+        micromark_with_options("{\n  1 + 1\n}", &mdx_options()),
+        "",
+        "should support an mdx flow expression spanning more than one line"
+    );
+}
+
+#[test]
+fn mdx_jsx_flow() {
+    assert_eq!(
+        micromark_with_options("<Chart data={sales} />", &mdx_options()),
+        "",
+        "should support a self-closing jsx flow element"
+    );
+
+    assert_eq!(
+        micromark_with_options("<Box>\n  <Chart data={sales} />\n</Box>", &mdx_options()),
+        "",
+        "should support a jsx flow element with children"
+    );
+
+    assert_eq!(
+        micromark_with_options("<Box><Box/></Box>", &mdx_options()),
+        "",
+        "should support a same-named self-closing child, without mistaking it for a nested opening tag"
+    );
+
+    assert_eq!(
+        micromark_with_options("<Box x={\"}\"} />", &mdx_options()),
+        "",
+        "should not mistake a `}` inside a quoted attribute value for the end of the expression"
+    );
+
+    assert_eq!(
+        micromark_with_options("<Box><Box></Box></Box>", &mdx_options()),
+        "",
+        "should match a same-named nested closing tag to its own opening tag"
+    );
+}